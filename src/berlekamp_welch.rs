@@ -0,0 +1,174 @@
+use crate::polynomial::Polynomial;
+use amcl_wrapper::field_elem::FieldElement;
+
+/// Robustly reconstruct the secret shared via a degree-`t` polynomial from the
+/// (possibly corrupted) shares `(x_i, y_i)`, tolerating up to `e` bad shares as long as
+/// `points.len() >= (t + 1) + 2*e` for the smallest such `e`. Returns `None` if no
+/// assignment of errors consistent with the shares could be found.
+///
+/// This is Berlekamp-Welch decoding: for an assumed error count `e`, it seeks a monic
+/// error-locator `E(x)` of degree `e` and `N(x) = P(x)*E(x)` of degree `< k + e` (where
+/// `k = t + 1`) such that `N(x_i) = y_i * E(x_i)` for every point. That is a homogeneous
+/// linear system in the unknown coefficients of `E` and `N`, solved with Gaussian
+/// elimination; `P = N / E` is then recovered via exact polynomial division and its
+/// constant term is the secret.
+pub fn reconstruct_secret(points: &[(FieldElement, FieldElement)], t: usize) -> Option<FieldElement> {
+    let n = points.len();
+    let k = t + 1;
+    let mut e = 0;
+    while k + 2 * e <= n {
+        if let Some(secret) = try_decode(points, k, e) {
+            return Some(secret);
+        }
+        e += 1;
+    }
+    None
+}
+
+/// Attempt Berlekamp-Welch decoding assuming exactly `e` errors among `points`.
+fn try_decode(points: &[(FieldElement, FieldElement)], k: usize, e: usize) -> Option<FieldElement> {
+    let n = points.len();
+    // e coefficients of E below its monic leading term, plus (k + e) coefficients of N.
+    let unknowns = k + 2 * e;
+    if n < unknowns {
+        return None;
+    }
+
+    // N(x_i) - y_i * E(x_i) = y_i * x_i^e, with E's monic leading term moved to the
+    // right hand side.
+    let mut rows = Vec::with_capacity(n);
+    for (x, y) in points {
+        let mut row = Vec::with_capacity(unknowns + 1);
+        let mut x_pow = FieldElement::one();
+        for _ in 0..(k + e) {
+            row.push(x_pow.clone());
+            x_pow = &x_pow * x;
+        }
+        let mut x_pow = FieldElement::one();
+        for _ in 0..e {
+            row.push(-(y * &x_pow));
+            x_pow = &x_pow * x;
+        }
+        row.push(y * &x_pow);
+        rows.push(row);
+    }
+
+    let solution = gaussian_eliminate(rows, unknowns)?;
+
+    let n_coeffs = solution[0..k + e].to_vec();
+    let mut e_coeffs = solution[k + e..unknowns].to_vec();
+    e_coeffs.push(FieldElement::one()); // restore E's monic leading term
+
+    let n_poly = Polynomial::from_coefficients(n_coeffs);
+    let e_poly = Polynomial::from_coefficients(e_coeffs);
+    let (quotient, remainder) = n_poly.div_rem(&e_poly);
+    if !(0..=remainder.degree()).all(|idx| remainder.coefficients()[idx].is_zero()) {
+        return None;
+    }
+    if quotient.degree() + 1 != k {
+        return None;
+    }
+    Some(quotient.coefficients()[0].clone())
+}
+
+/// Solve the linear system given by `rows`, each an augmented row of `unknowns`
+/// coefficients followed by its right hand side. Returns `None` if the system has fewer
+/// than `unknowns` independent equations, or if it is inconsistent (which signals that
+/// the assumed error count `e` was wrong).
+fn gaussian_eliminate(mut rows: Vec<Vec<FieldElement>>, unknowns: usize) -> Option<Vec<FieldElement>> {
+    let n = rows.len();
+    let mut pivot_row = 0;
+    for col in 0..unknowns {
+        let pivot = (pivot_row..n).find(|&r| !rows[r][col].is_zero())?;
+        rows.swap(pivot_row, pivot);
+
+        let mut inv = rows[pivot_row][col].clone();
+        inv.inverse_mut();
+        for c in col..=unknowns {
+            rows[pivot_row][c] = &rows[pivot_row][c] * &inv;
+        }
+
+        for r in 0..n {
+            if r == pivot_row {
+                continue;
+            }
+            let factor = rows[r][col].clone();
+            if factor.is_zero() {
+                continue;
+            }
+            for c in col..=unknowns {
+                let sub = &rows[pivot_row][c] * &factor;
+                rows[r][c] = &rows[r][c] - &sub;
+            }
+        }
+        pivot_row += 1;
+    }
+
+    // Any remaining, dependent rows must agree with zero on the right hand side too.
+    for row in rows.iter().skip(pivot_row) {
+        if !row[unknowns].is_zero() {
+            return None;
+        }
+    }
+
+    Some((0..unknowns).map(|i| rows[i][unknowns].clone()).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `n` shares `(1, p(1)), (2, p(2)), ..., (n, p(n))` of `poly`.
+    fn shares(poly: &Polynomial, n: usize) -> Vec<(FieldElement, FieldElement)> {
+        (1..=n)
+            .map(|x| {
+                let x = FieldElement::from(x as u64);
+                let y = poly.eval(&x);
+                (x, y)
+            })
+            .collect()
+    }
+
+    /// Corrupt the `y` coordinate of the first `e` shares.
+    fn corrupt(points: &mut [(FieldElement, FieldElement)], e: usize) {
+        for point in points.iter_mut().take(e) {
+            point.1 = &point.1 + &FieldElement::one();
+        }
+    }
+
+    #[test]
+    fn test_reconstruct_with_no_errors() {
+        let t = 2;
+        let poly = Polynomial::random(t);
+        let points = shares(&poly, 2 * t + 1);
+        assert_eq!(reconstruct_secret(&points, t), Some(poly.coefficients()[0].clone()));
+    }
+
+    #[test]
+    fn test_reconstruct_with_maximum_tolerable_errors() {
+        let t = 2;
+        let k = t + 1;
+        let n = 7; // tolerates e errors while k + 2e <= n, i.e. e <= 2
+        let e = (n - k) / 2;
+
+        let poly = Polynomial::random(t);
+        let mut points = shares(&poly, n);
+        corrupt(&mut points, e);
+
+        assert_eq!(reconstruct_secret(&points, t), Some(poly.coefficients()[0].clone()));
+    }
+
+    #[test]
+    fn test_reconstruct_fails_with_one_too_many_errors() {
+        let t = 2;
+        let k = t + 1;
+        let n = 7;
+        let e = (n - k) / 2 + 1; // one more than the tolerable maximum
+
+        let poly = Polynomial::random(t);
+        let mut points = shares(&poly, n);
+        corrupt(&mut points, e);
+
+        assert_eq!(reconstruct_secret(&points, t), None);
+    }
+}
@@ -0,0 +1,185 @@
+use amcl_wrapper::field_elem::{FieldElement, FieldElementVector};
+
+/// Error returned when an `EvaluationDomain` cannot be built.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EvaluationDomainError {
+    /// `n` is not a power of two.
+    NotAPowerOfTwo(usize),
+    /// The supplied element is not a primitive `n`-th root of unity.
+    NotARootOfUnity(usize),
+}
+
+/// A radix-2 multiplicative subgroup of size `n`, together with the powers of its
+/// generator (and their inverses) needed to run the FFT/IFFT. Evaluating or
+/// interpolating a polynomial over this domain costs `O(n log n)` instead of the
+/// `O(n^2)` cost of calling `Polynomial::eval` once per point or pairwise Lagrange
+/// interpolation.
+pub struct EvaluationDomain {
+    n: usize,
+    /// `1, omega, omega^2, ..., omega^{n-1}`
+    roots: FieldElementVector,
+    /// `1, omega^-1, omega^-2, ..., omega^-(n-1)`
+    inv_roots: FieldElementVector,
+    n_inv: FieldElement,
+}
+
+impl EvaluationDomain {
+    /// Build a size-`n` evaluation domain from a primitive `n`-th root of unity of the
+    /// field. `n` must be a power of two dividing the field's multiplicative-group
+    /// order; the caller supplies `root_of_unity` since the field's 2-adicity is a
+    /// property of the curve backing `amcl_wrapper`, not something this crate derives.
+    pub fn new(n: usize, root_of_unity: FieldElement) -> Result<Self, EvaluationDomainError> {
+        if n == 0 || (n & (n - 1)) != 0 {
+            return Err(EvaluationDomainError::NotAPowerOfTwo(n));
+        }
+
+        let mut roots = Vec::with_capacity(n);
+        let mut cur = FieldElement::one();
+        for _ in 0..n {
+            roots.push(cur.clone());
+            cur = &cur * &root_of_unity;
+        }
+        if cur != FieldElement::one() {
+            return Err(EvaluationDomainError::NotARootOfUnity(n));
+        }
+        // `root_of_unity^n == 1` alone doesn't rule out an order that is a smaller
+        // power-of-two divisor of `n` (e.g. n/2); check that `n` is in fact the order by
+        // confirming the element doesn't already collapse to 1 at the halfway point.
+        // `roots[n / 2] == root_of_unity^(n / 2)` since `roots[k] = root_of_unity^k`.
+        if n > 1 && roots[n / 2] == FieldElement::one() {
+            return Err(EvaluationDomainError::NotARootOfUnity(n));
+        }
+
+        let mut root_inv = root_of_unity;
+        root_inv.inverse_mut();
+        let mut inv_roots = Vec::with_capacity(n);
+        let mut cur = FieldElement::one();
+        for _ in 0..n {
+            inv_roots.push(cur.clone());
+            cur = &cur * &root_inv;
+        }
+
+        let mut n_inv = FieldElement::from(n as u64);
+        n_inv.inverse_mut();
+
+        Ok(Self {
+            n,
+            roots: FieldElementVector::from(roots),
+            inv_roots: FieldElementVector::from(inv_roots),
+            n_inv,
+        })
+    }
+
+    pub fn size(&self) -> usize {
+        self.n
+    }
+
+    /// Evaluate coefficients (padded with zeros, or truncated, to length `n`) at every
+    /// point of the domain.
+    pub fn fft(&self, coeffs: &FieldElementVector) -> FieldElementVector {
+        let mut a: Vec<FieldElement> = (0..self.n)
+            .map(|i| if i < coeffs.len() { coeffs[i].clone() } else { FieldElement::zero() })
+            .collect();
+        Self::fft_in_place(&mut a, &self.roots);
+        FieldElementVector::from(a)
+    }
+
+    /// Recover the coefficients of the (unique, degree `< n`) polynomial agreeing with
+    /// `evals` on every point of the domain.
+    pub fn ifft(&self, evals: &FieldElementVector) -> FieldElementVector {
+        let mut a: Vec<FieldElement> = (0..self.n).map(|i| evals[i].clone()).collect();
+        Self::fft_in_place(&mut a, &self.inv_roots);
+        for x in a.iter_mut() {
+            *x = &*x * &self.n_inv;
+        }
+        FieldElementVector::from(a)
+    }
+
+    /// In-place iterative radix-2 Cooley-Tukey FFT (decimation in time). `roots[k]` must
+    /// hold the domain generator (or its inverse, for the IFFT) raised to the `k`-th power.
+    fn fft_in_place(a: &mut [FieldElement], roots: &FieldElementVector) {
+        let n = a.len();
+
+        // Bit-reversal permutation.
+        let mut j = 0;
+        for i in 1..n {
+            let mut bit = n >> 1;
+            while j & bit != 0 {
+                j ^= bit;
+                bit >>= 1;
+            }
+            j ^= bit;
+            if i < j {
+                a.swap(i, j);
+            }
+        }
+
+        let mut len = 2;
+        while len <= n {
+            let half = len / 2;
+            let step = n / len;
+            for start in (0..n).step_by(len) {
+                for k in 0..half {
+                    let w = &roots[k * step];
+                    let u = a[start + k].clone();
+                    let v = &a[start + k + half] * w;
+                    a[start + k] = &u + &v;
+                    a[start + k + half] = &u - &v;
+                }
+            }
+            len <<= 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rejects_non_power_of_two_size() {
+        let err = EvaluationDomain::new(3, FieldElement::one()).unwrap_err();
+        assert_eq!(err, EvaluationDomainError::NotAPowerOfTwo(3));
+    }
+
+    #[test]
+    fn test_rejects_non_primitive_root_of_unity() {
+        // `1` satisfies `1^n == 1` for any `n`, but its order is 1, not `n`.
+        let err = EvaluationDomain::new(4, FieldElement::one()).unwrap_err();
+        assert_eq!(err, EvaluationDomainError::NotARootOfUnity(4));
+    }
+
+    #[test]
+    fn test_trivial_domain_round_trips() {
+        let domain = EvaluationDomain::new(1, FieldElement::one()).unwrap();
+        let coeffs = FieldElementVector::from(vec![FieldElement::from(42u64)]);
+
+        let evals = domain.fft(&coeffs);
+        assert_eq!(evals[0], coeffs[0]);
+
+        let recovered = domain.ifft(&evals);
+        assert_eq!(recovered[0], coeffs[0]);
+    }
+
+    #[test]
+    fn test_fft_matches_eval_and_round_trips_for_domain_size_two() {
+        use crate::polynomial::Polynomial;
+
+        // `-1` has order 2 (the field's characteristic isn't 2), a genuine primitive
+        // square root of unity, unlike the trivial `n = 1` domain above.
+        let neg_one = -FieldElement::one();
+        let domain = EvaluationDomain::new(2, neg_one.clone()).unwrap();
+
+        let poly = Polynomial::random(1);
+        let evals = poly.evaluate_over_domain(&domain);
+
+        // `fft` evaluates at the domain points `1, omega, ..., omega^{n-1}`.
+        assert_eq!(evals[0], poly.eval(&FieldElement::one()));
+        assert_eq!(evals[1], poly.eval(&neg_one));
+
+        let recovered = Polynomial::interpolate_over_domain(&evals, &domain);
+        for i in 0..=poly.degree() {
+            assert_eq!(recovered.coefficients()[i], poly.coefficients()[i]);
+        }
+    }
+}
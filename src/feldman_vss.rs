@@ -0,0 +1,67 @@
+use crate::polynomial::Polynomial;
+use amcl_wrapper::field_elem::FieldElement;
+use amcl_wrapper::group_elem::GroupElement;
+
+/// Feldman's Verifiable Secret Sharing (VSS). The dealer commits to each coefficient of
+/// the sharing `Polynomial` as `C_j = g^{a_j}` and publishes the commitment vector
+/// alongside the shares, letting every shareholder check that the share they were handed
+/// is consistent with the committed polynomial without having to trust the dealer.
+pub struct FeldmanVSS;
+
+impl FeldmanVSS {
+    /// Commit to each coefficient of `poly`, producing `[C_0, C_1, ..., C_degree]` where
+    /// `C_j = g^{a_j}`.
+    pub fn commit<G: GroupElement>(poly: &Polynomial, g: &G) -> Vec<G> {
+        (0..=poly.degree()).map(|j| g * &poly.coefficients()[j]).collect()
+    }
+
+    /// Check that `share = poly.eval(i)` for the polynomial committed to in
+    /// `commitments`, i.e. that `g^share == product_j C_j^{i^j}`. The right hand side is
+    /// accumulated with the same Horner's method `Polynomial::eval` uses to accumulate
+    /// powers of `i`, reading commitments from the highest degree down.
+    pub fn verify_share<G: GroupElement>(
+        commitments: &[G],
+        i: &FieldElement,
+        share: &FieldElement,
+        g: &G,
+    ) -> bool {
+        if commitments.is_empty() {
+            return false;
+        }
+        let mut rhs = commitments[commitments.len() - 1].clone();
+        for c in commitments[..commitments.len() - 1].iter().rev() {
+            rhs = &(&rhs * i) + c;
+        }
+        let lhs = g * share;
+        lhs == rhs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use amcl_wrapper::group_elem_g1::G1;
+
+    #[test]
+    fn test_commit_and_verify_share() {
+        let degree = 5;
+        let poly = Polynomial::random(degree);
+        let g = G1::generator();
+        let commitments = FeldmanVSS::commit(&poly, &g);
+        assert_eq!(commitments.len(), degree + 1);
+
+        for x in 1..=3 {
+            let i = FieldElement::from(x as u64);
+            let share = poly.eval(&i);
+            assert!(FeldmanVSS::verify_share(&commitments, &i, &share, &g));
+
+            // A tampered share must not verify.
+            let bad_share = &share + &FieldElement::one();
+            assert!(!FeldmanVSS::verify_share(&commitments, &i, &bad_share, &g));
+
+            // Nor must the correct share verified against the wrong index.
+            let wrong_index = &i + &FieldElement::one();
+            assert!(!FeldmanVSS::verify_share(&commitments, &wrong_index, &share, &g));
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use crate::polynomial::Polynomial;
+use amcl_wrapper::extension_field_gt::GT;
+use amcl_wrapper::field_elem::FieldElement;
+use amcl_wrapper::group_elem::GroupElement;
+use amcl_wrapper::group_elem_g1::{G1Vector, G1};
+use amcl_wrapper::group_elem_g2::G2;
+
+/// A structured reference string from a trusted setup: the powers of a toxic-waste
+/// scalar `tau` in `G1` up to the supported degree, and `tau` itself in `G2`.
+pub struct SRS {
+    /// `[g1^{tau^0}, g1^{tau^1}, ..., g1^{tau^degree}]`
+    powers_of_tau_g1: G1Vector,
+    g2: G2,
+    tau_g2: G2,
+}
+
+impl SRS {
+    /// Run a (non-distributed, single-party) trusted setup supporting polynomials up to
+    /// `degree`. The caller must discard `tau` afterwards; a real deployment would
+    /// generate this SRS via an MPC ceremony instead of a single party choosing `tau`.
+    pub fn trusted_setup(degree: usize, tau: &FieldElement, g1: &G1, g2: &G2) -> Self {
+        let mut powers = Vec::with_capacity(degree + 1);
+        let mut cur = FieldElement::one();
+        for _ in 0..=degree {
+            powers.push(g1 * &cur);
+            cur = &cur * tau;
+        }
+        Self {
+            powers_of_tau_g1: G1Vector::from(powers),
+            g2: g2.clone(),
+            tau_g2: g2 * tau,
+        }
+    }
+
+    pub fn max_degree(&self) -> usize {
+        self.powers_of_tau_g1.len() - 1
+    }
+}
+
+/// A KZG commitment to a polynomial. Unlike `FeldmanVSS`, whose commitment and
+/// verification both scale with the polynomial's degree, a `KZGCommitment` is a single
+/// group element and each share comes with a constant-size opening proof.
+pub struct KZGCommitment {
+    commitment: G1,
+}
+
+impl KZGCommitment {
+    /// Commit to `poly` as `C = prod_j (g1^{tau^j})^{a_j}`, the multi-exponentiation of
+    /// the SRS powers by `poly`'s coefficients.
+    pub fn commit(poly: &Polynomial, srs: &SRS) -> Self {
+        assert!(poly.degree() <= srs.max_degree(), "polynomial degree exceeds the SRS");
+        let mut commitment = G1::identity();
+        for j in 0..=poly.degree() {
+            commitment = &commitment + &(&srs.powers_of_tau_g1[j] * &poly.coefficients()[j]);
+        }
+        Self { commitment }
+    }
+
+    pub fn value(&self) -> &G1 {
+        &self.commitment
+    }
+
+    /// Produce the share `poly(i)` together with a constant-size opening proof that it
+    /// is consistent with this commitment: the commitment to the quotient
+    /// `q(x) = (p(x) - p(i)) / (x - i)`, which divides evenly because `i` is a root of
+    /// `p(x) - p(i)`.
+    pub fn open(poly: &Polynomial, i: &FieldElement, srs: &SRS) -> (FieldElement, G1) {
+        assert!(poly.degree() <= srs.max_degree(), "polynomial degree exceeds the SRS");
+        let share = poly.eval(i);
+
+        let mut numerator_coeffs: Vec<FieldElement> =
+            (0..=poly.degree()).map(|k| poly.coefficients()[k].clone()).collect();
+        numerator_coeffs[0] = &numerator_coeffs[0] - &share;
+        let numerator = Polynomial::from_coefficients(numerator_coeffs);
+
+        let neg_i = -i.clone();
+        let divisor = Polynomial::from_coefficients(vec![neg_i, FieldElement::one()]);
+        let (quotient, _remainder) = numerator.div_rem(&divisor);
+
+        let mut witness = G1::identity();
+        for j in 0..=quotient.degree() {
+            witness = &witness + &(&srs.powers_of_tau_g1[j] * &quotient.coefficients()[j]);
+        }
+        (share, witness)
+    }
+
+    /// Verify that `share = p(i)` for the polynomial committed to in `self`, by checking
+    /// the pairing equation `e(C - g1^share, g2) == e(W, g2^tau - g2^i)`.
+    pub fn verify(&self, i: &FieldElement, share: &FieldElement, witness: &G1, srs: &SRS) -> bool {
+        let lhs_g1 = &self.commitment - &(&srs.powers_of_tau_g1[0] * share);
+        let rhs_g2 = &srs.tau_g2 - &(&srs.g2 * i);
+        GT::ate_pairing(&lhs_g1, &srs.g2) == GT::ate_pairing(witness, &rhs_g2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polynomial::Polynomial;
+
+    #[test]
+    fn test_commit_open_and_verify() {
+        let degree = 4;
+        let poly = Polynomial::random(degree);
+        let tau = FieldElement::random();
+        let g1 = G1::generator();
+        let g2 = G2::generator();
+        let srs = SRS::trusted_setup(degree, &tau, &g1, &g2);
+
+        let commitment = KZGCommitment::commit(&poly, &srs);
+        let i = FieldElement::from(3u64);
+        let (share, witness) = KZGCommitment::open(&poly, &i, &srs);
+
+        assert_eq!(share, poly.eval(&i));
+        assert!(commitment.verify(&i, &share, &witness, &srs));
+
+        // A tampered share must not verify.
+        let bad_share = &share + &FieldElement::one();
+        assert!(!commitment.verify(&i, &bad_share, &witness, &srs));
+
+        // Nor must a tampered witness.
+        let bad_witness = &witness + &g1;
+        assert!(!commitment.verify(&i, &share, &bad_witness, &srs));
+    }
+}
@@ -0,0 +1,5 @@
+pub mod polynomial;
+pub mod feldman_vss;
+pub mod berlekamp_welch;
+pub mod evaluation_domain;
+pub mod kzg;
@@ -1,3 +1,4 @@
+use crate::evaluation_domain::EvaluationDomain;
 use amcl_wrapper::field_elem::{FieldElement, FieldElementVector};
 use std::collections::HashSet;
 
@@ -57,6 +58,204 @@ impl Polynomial {
         // (x_coords[0]) * (x_coords[1]) * ... / ((x_coords[0] - i) * (x_coords[1] - i) * ...)
         numerator * denominator
     }
+
+    /// Return the Lagrange basis polynomial `L_i` (for the node set `x_coords`)
+    /// evaluated at an arbitrary `x`, via the barycentric form. This generalizes
+    /// `lagrange_basis_at_0`, which only evaluates at `x = 0`. This recomputes the
+    /// barycentric weights from scratch, so it costs `O(m^2)` for a single call; for
+    /// repeated evaluations against the same `x_coords`, build a `BarycentricWeights`
+    /// once and call `eval_basis` instead, which is `O(m)` per evaluation.
+    pub fn lagrange_basis_at(x_coords: &[FieldElement], i: usize, x: &FieldElement) -> FieldElement {
+        BarycentricWeights::new(x_coords).eval_basis(i, x)
+    }
+
+    /// Reconstruct the unique polynomial of degree `< points.len()` passing through
+    /// every `(x, y)` pair, via explicit Lagrange interpolation:
+    /// `P(x) = sum_i y_i * L_i(x)`, where each basis polynomial
+    /// `L_i(x) = prod_{j != i} (x - x_j)/(x_i - x_j)` is built coefficient-by-coefficient
+    /// with `mul`. Unlike `lagrange_basis_at_0`/`lagrange_basis_at`, which only recover
+    /// the polynomial's value at a single point, this recovers the whole polynomial —
+    /// needed for schemes like proactive/refresh secret sharing.
+    pub fn interpolate(points: &[(FieldElement, FieldElement)]) -> Self {
+        let mut result = Self::from_coefficients(vec![FieldElement::zero()]);
+        for (i, (x_i, y_i)) in points.iter().enumerate() {
+            let mut basis = Self::from_coefficients(vec![FieldElement::one()]);
+            let mut denominator = FieldElement::one();
+            for (j, (x_j, _)) in points.iter().enumerate() {
+                if i == j {
+                    continue;
+                }
+                let neg_x_j = -x_j.clone();
+                basis = basis.mul(&Self::from_coefficients(vec![neg_x_j, FieldElement::one()]));
+                denominator = &denominator * &(x_i - x_j);
+            }
+            denominator.inverse_mut();
+            let scalar = y_i * &denominator;
+            let scaled = (0..=basis.degree()).map(|k| &basis.0[k] * &scalar).collect();
+            result = result.add(&Self::from_coefficients(scaled));
+        }
+        result
+    }
+
+    /// Build a polynomial from coefficients (lowest degree first), trimming any trailing
+    /// zero coefficients. The zero polynomial is represented by a single zero coefficient.
+    pub(crate) fn from_coefficients(mut coeffs: Vec<FieldElement>) -> Self {
+        while coeffs.len() > 1 && coeffs[coeffs.len() - 1].is_zero() {
+            coeffs.pop();
+        }
+        if coeffs.is_empty() {
+            coeffs.push(FieldElement::zero());
+        }
+        Self(FieldElementVector::from(coeffs))
+    }
+
+    fn is_zero_poly(&self) -> bool {
+        (0..self.0.len()).all(|i| self.0[i].is_zero())
+    }
+
+    /// Add two polynomials coefficient-wise.
+    pub fn add(&self, other: &Self) -> Self {
+        let max_len = self.0.len().max(other.0.len());
+        let coeffs = (0..max_len)
+            .map(|i| {
+                let a = if i < self.0.len() { self.0[i].clone() } else { FieldElement::zero() };
+                let b = if i < other.0.len() { other.0[i].clone() } else { FieldElement::zero() };
+                &a + &b
+            })
+            .collect();
+        Self::from_coefficients(coeffs)
+    }
+
+    /// Subtract `other` from `self` coefficient-wise.
+    pub fn sub(&self, other: &Self) -> Self {
+        let max_len = self.0.len().max(other.0.len());
+        let coeffs = (0..max_len)
+            .map(|i| {
+                let a = if i < self.0.len() { self.0[i].clone() } else { FieldElement::zero() };
+                let b = if i < other.0.len() { other.0[i].clone() } else { FieldElement::zero() };
+                &a - &b
+            })
+            .collect();
+        Self::from_coefficients(coeffs)
+    }
+
+    /// Multiply two polynomials, producing a result of degree `self.degree() + other.degree()`.
+    pub fn mul(&self, other: &Self) -> Self {
+        let mut coeffs = vec![FieldElement::zero(); self.0.len() + other.0.len() - 1];
+        for i in 0..self.0.len() {
+            if self.0[i].is_zero() {
+                continue;
+            }
+            for j in 0..other.0.len() {
+                coeffs[i + j] = &coeffs[i + j] + &(&self.0[i] * &other.0[j]);
+            }
+        }
+        Self::from_coefficients(coeffs)
+    }
+
+    /// Divide `self` by `divisor` via schoolbook long division, returning
+    /// `(quotient, remainder)` such that `self == quotient.mul(divisor).add(&remainder)`
+    /// and `remainder.degree() < divisor.degree()` (or `remainder` is the zero
+    /// polynomial). Panics if `divisor` is the zero polynomial.
+    pub fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        assert!(!divisor.is_zero_poly(), "division by the zero polynomial");
+
+        if self.is_zero_poly() || self.degree() < divisor.degree() {
+            let coeffs = (0..self.0.len()).map(|i| self.0[i].clone()).collect();
+            return (Self::from_coefficients(vec![FieldElement::zero()]), Self::from_coefficients(coeffs));
+        }
+
+        let mut remainder: Vec<FieldElement> = (0..self.0.len()).map(|i| self.0[i].clone()).collect();
+        let div_deg = divisor.degree();
+        let mut lead_inv = divisor.0[div_deg].clone();
+        lead_inv.inverse_mut();
+
+        let quot_deg = self.degree() - div_deg;
+        let mut quotient = vec![FieldElement::zero(); quot_deg + 1];
+
+        for shift in (0..=quot_deg).rev() {
+            let rem_deg = shift + div_deg;
+            if rem_deg >= remainder.len() || remainder[rem_deg].is_zero() {
+                continue;
+            }
+            let coeff = &remainder[rem_deg] * &lead_inv;
+            quotient[shift] = coeff.clone();
+            for j in 0..=div_deg {
+                remainder[shift + j] = &remainder[shift + j] - &(&divisor.0[j] * &coeff);
+            }
+        }
+
+        (Self::from_coefficients(quotient), Self::from_coefficients(remainder))
+    }
+
+    /// Evaluate this polynomial at every point of `domain` in `O(n log n)` via FFT,
+    /// instead of the `O(n^2)` cost of calling `eval` once per point.
+    pub fn evaluate_over_domain(&self, domain: &EvaluationDomain) -> FieldElementVector {
+        // Every domain point satisfies `x^n = 1`, so coefficient `i` contributes to
+        // `x^(i mod n)`; folding keeps the result exact when the degree is `>= n`.
+        let n = domain.size();
+        let mut folded = vec![FieldElement::zero(); n.min(self.0.len())];
+        for i in 0..self.0.len() {
+            folded[i % n] = &folded[i % n] + &self.0[i];
+        }
+        domain.fft(&FieldElementVector::from(folded))
+    }
+
+    /// Recover the polynomial of degree `< domain.size()` whose evaluations over
+    /// `domain` are `evals`; the inverse of `evaluate_over_domain`.
+    pub fn interpolate_over_domain(evals: &FieldElementVector, domain: &EvaluationDomain) -> Self {
+        let coeffs = domain.ifft(evals);
+        Self::from_coefficients((0..coeffs.len()).map(|i| coeffs[i].clone()).collect())
+    }
+}
+
+/// Barycentric weights for a fixed node set `x_coords`, precomputed once so that
+/// evaluating the Lagrange basis at many different points costs `O(m)` each afterwards,
+/// instead of redoing the `O(m^2)` pairwise products every time.
+pub struct BarycentricWeights {
+    x_coords: Vec<FieldElement>,
+    weights: Vec<FieldElement>,
+}
+
+impl BarycentricWeights {
+    /// Precompute `w_i = 1 / prod_{j != i} (x_i - x_j)` for each node in `x_coords`.
+    pub fn new(x_coords: &[FieldElement]) -> Self {
+        let weights = (0..x_coords.len())
+            .map(|i| {
+                let mut w = FieldElement::one();
+                for (j, x_j) in x_coords.iter().enumerate() {
+                    if j != i {
+                        w = &w * &(&x_coords[i] - x_j);
+                    }
+                }
+                w.inverse_mut();
+                w
+            })
+            .collect();
+        Self { x_coords: x_coords.to_vec(), weights }
+    }
+
+    /// Evaluate the Lagrange basis polynomial `L_i` at `x`, using the cached weights:
+    /// `L_i(x) = (w_i/(x - x_i)) / sum_j (w_j/(x - x_j))`.
+    pub fn eval_basis(&self, i: usize, x: &FieldElement) -> FieldElement {
+        if let Some(j) = self.x_coords.iter().position(|x_j| x_j == x) {
+            return if j == i { FieldElement::one() } else { FieldElement::zero() };
+        }
+
+        let term = |idx: usize| -> FieldElement {
+            let mut diff = x - &self.x_coords[idx];
+            diff.inverse_mut();
+            &self.weights[idx] * &diff
+        };
+
+        let numerator = term(i);
+        let mut denominator = FieldElement::zero();
+        for j in 0..self.x_coords.len() {
+            denominator = &denominator + &term(j);
+        }
+        denominator.inverse_mut();
+        &numerator * &denominator
+    }
 }
 
 #[cfg(test)]
@@ -78,4 +277,111 @@ mod tests {
             assert_eq!(poly.eval(&FieldElement::one()), coeffs.sum());
         }
     }
+
+    #[test]
+    fn test_add_sub_mul() {
+        let p1 = Polynomial::random(4);
+        let p2 = Polynomial::random(2);
+
+        // add then sub recovers the original
+        let recovered = p1.add(&p2).sub(&p2);
+        for i in 0..=p1.degree() {
+            assert_eq!(recovered.coefficients()[i], p1.coefficients()[i]);
+        }
+
+        // mul raises the degree as expected and is consistent pointwise
+        let product = p1.mul(&p2);
+        assert_eq!(product.degree(), p1.degree() + p2.degree());
+        let x = FieldElement::from(7u64);
+        assert_eq!(product.eval(&x), &p1.eval(&x) * &p2.eval(&x));
+    }
+
+    #[test]
+    fn test_div_rem_reconstructs_dividend() {
+        let p1 = Polynomial::random(5);
+        let p2 = Polynomial::random(2);
+        let dividend = p1.mul(&p2);
+
+        let (quotient, remainder) = dividend.div_rem(&p2);
+        assert_eq!(quotient.degree(), p1.degree());
+        assert!((0..=remainder.degree()).all(|i| remainder.coefficients()[i].is_zero()));
+
+        let reconstructed = quotient.mul(&p2).add(&remainder);
+        for i in 0..=dividend.degree() {
+            assert_eq!(reconstructed.coefficients()[i], dividend.coefficients()[i]);
+        }
+    }
+
+    #[test]
+    fn test_div_rem_with_lower_degree_dividend() {
+        let dividend = Polynomial::random(1);
+        let divisor = Polynomial::random(3);
+
+        let (quotient, remainder) = dividend.div_rem(&divisor);
+        assert!(quotient.coefficients()[0].is_zero());
+        for i in 0..=dividend.degree() {
+            assert_eq!(remainder.coefficients()[i], dividend.coefficients()[i]);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "division by the zero polynomial")]
+    fn test_div_rem_by_zero_panics() {
+        let dividend = Polynomial::random(3);
+        let zero = Polynomial::from_coefficients(vec![FieldElement::zero()]);
+        dividend.div_rem(&zero);
+    }
+
+    #[test]
+    fn test_interpolate_recovers_known_polynomial() {
+        let poly = Polynomial::random(4);
+        let points: Vec<(FieldElement, FieldElement)> = (1..=5)
+            .map(|x| {
+                let x = FieldElement::from(x as u64);
+                let y = poly.eval(&x);
+                (x, y)
+            })
+            .collect();
+
+        let recovered = Polynomial::interpolate(&points);
+        assert_eq!(recovered.degree(), poly.degree());
+
+        // Agrees with the original polynomial at a held-out point.
+        let held_out = FieldElement::from(100u64);
+        assert_eq!(recovered.eval(&held_out), poly.eval(&held_out));
+    }
+
+    #[test]
+    fn test_lagrange_basis_at_is_kronecker_delta_on_nodes() {
+        let x_coords: Vec<FieldElement> = (0..4).map(|x| FieldElement::from(x as u64)).collect();
+        let weights = BarycentricWeights::new(&x_coords);
+
+        for (i, x_i) in x_coords.iter().enumerate() {
+            assert_eq!(Polynomial::lagrange_basis_at(&x_coords, i, x_i), FieldElement::one());
+            assert_eq!(weights.eval_basis(i, x_i), FieldElement::one());
+            for (j, x_j) in x_coords.iter().enumerate() {
+                if j != i {
+                    assert_eq!(Polynomial::lagrange_basis_at(&x_coords, i, x_j), FieldElement::zero());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_barycentric_weights_reproduce_interpolated_value() {
+        let x_coords: Vec<FieldElement> = (0..4).map(|x| FieldElement::from(x as u64)).collect();
+        let y_coords: Vec<FieldElement> = (0..4).map(|y| FieldElement::from((3 * y + 1) as u64)).collect();
+        let points: Vec<(FieldElement, FieldElement)> =
+            x_coords.iter().cloned().zip(y_coords.iter().cloned()).collect();
+
+        let poly = Polynomial::interpolate(&points);
+        let weights = BarycentricWeights::new(&x_coords);
+
+        let x = FieldElement::from(42u64);
+        let mut expected = FieldElement::zero();
+        for (i, y_i) in y_coords.iter().enumerate() {
+            expected = &expected + &(y_i * &weights.eval_basis(i, &x));
+        }
+        assert_eq!(poly.eval(&x), expected);
+    }
 }
\ No newline at end of file